@@ -49,9 +49,46 @@ declare_types! {
                 Err(e) => cx.throw_error(format!("{}", e))
             }
         }
+
+        /// to_file(path: string)
+        ///
+        /// Serializes the model (vocab and merges inlined) to a single,
+        /// self-describing JSON file, avoiding the multi-file `save` layout.
+        method to_file(mut cx) {
+            let path = cx.argument::<JsString>(0)?.value();
+
+            let this = cx.this();
+            let guard = cx.lock();
+            let data = this.borrow(&guard).model.execute(|model| {
+                serde_json::to_string(model.unwrap())
+            });
+
+            match data.and_then(|data| std::fs::write(&path, data)) {
+                Ok(_) => Ok(cx.undefined().upcast()),
+                Err(e) => cx.throw_error(format!("{}", e)),
+            }
+        }
     }
 }
 
+/// from_file(path: string)
+///
+/// Loads a model previously written by `to_file` from a single JSON file,
+/// reconstructing the concrete type by its serde tag.
+pub fn from_file(mut cx: FunctionContext) -> JsResult<JsModel> {
+    let path = cx.argument::<JsString>(0)?.value();
+
+    let data = std::fs::read_to_string(&path).or_else(|e| cx.throw_error(format!("{}", e)))?;
+    let model: Box<dyn tk::tokenizer::Model + Sync> =
+        serde_json::from_str(&data).or_else(|e| cx.throw_error(format!("{}", e)))?;
+
+    let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    js_model.borrow_mut(&guard).model.to_owned(model);
+
+    Ok(js_model)
+}
+
 /// bpe_from_files(vocab: String, merges: String, options?: {
 ///   cache_capacity?: number,
 ///   dropout?: number,
@@ -178,6 +215,46 @@ pub fn wordpiece_empty(mut cx: FunctionContext) -> JsResult<JsModel> {
     Ok(model)
 }
 
+/// unigram_from_file(vocab: String, options?: {
+///   unkToken?: String = "<unk>",
+/// })
+pub fn unigram_from_file(mut cx: FunctionContext) -> JsResult<JsModel> {
+    let vocab = cx.argument::<JsString>(0)?.value() as String;
+    let options = cx.argument_opt(1);
+
+    let mut unk_token = String::from("<unk>");
+
+    if let Some(options) = options {
+        if let Ok(options) = options.downcast::<JsObject>() {
+            if let Ok(unk) = options.get(&mut cx, "unkToken") {
+                if let Err(_) = unk.downcast::<JsUndefined>() {
+                    unk_token = unk.downcast::<JsString>().or_throw(&mut cx)?.value() as String;
+                }
+            }
+        }
+    }
+
+    let unigram = tk::models::unigram::Unigram::from_file(&vocab, unk_token)
+        .or_else(|e| cx.throw_error(format!("{}", e)))?;
+
+    let mut model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    model.borrow_mut(&guard).model.to_owned(Box::new(unigram));
+
+    Ok(model)
+}
+
+/// unigram_empty()
+pub fn unigram_empty(mut cx: FunctionContext) -> JsResult<JsModel> {
+    let mut model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
+    let unigram = tk::models::unigram::Unigram::default();
+
+    let guard = cx.lock();
+    model.borrow_mut(&guard).model.to_owned(Box::new(unigram));
+
+    Ok(model)
+}
+
 /// Register everything here
 pub fn register(m: &mut ModuleContext, prefix: &str) -> NeonResult<()> {
     m.export_function(&format!("{}_BPE_from_files", prefix), bpe_from_files)?;
@@ -187,5 +264,8 @@ pub fn register(m: &mut ModuleContext, prefix: &str) -> NeonResult<()> {
         wordpiece_from_files,
     )?;
     m.export_function(&format!("{}_WordPiece_empty", prefix), wordpiece_empty)?;
+    m.export_function(&format!("{}_Unigram_from_file", prefix), unigram_from_file)?;
+    m.export_function(&format!("{}_Unigram_empty", prefix), unigram_empty)?;
+    m.export_function(&format!("{}_from_file", prefix), from_file)?;
     Ok(())
 }
@@ -0,0 +1,43 @@
+extern crate tokenizers as tk;
+
+use neon::prelude::*;
+
+/// from_file(path: string): string
+///
+/// Reads a single, self-describing tokenizer JSON file, validates every
+/// component and returns the canonical JSON representation.
+fn from_file(mut cx: FunctionContext) -> JsResult<JsString> {
+    let path = cx.argument::<JsString>(0)?.value();
+
+    let tokenizer = tk::serialization::SerializedTokenizer::from_file(&path)
+        .or_else(|e| cx.throw_error(format!("{}", e)))?;
+    let data = tokenizer
+        .to_string(true)
+        .or_else(|e| cx.throw_error(format!("{}", e)))?;
+
+    Ok(cx.string(data))
+}
+
+/// to_file(path: string, data: string)
+///
+/// Validates a canonical tokenizer JSON document and writes the whole pipeline
+/// to a single file.
+fn to_file(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let path = cx.argument::<JsString>(0)?.value();
+    let data = cx.argument::<JsString>(1)?.value();
+
+    let tokenizer = tk::serialization::SerializedTokenizer::from_str(&data)
+        .or_else(|e| cx.throw_error(format!("{}", e)))?;
+    tokenizer
+        .to_file(&path)
+        .or_else(|e| cx.throw_error(format!("{}", e)))?;
+
+    Ok(cx.undefined())
+}
+
+/// Register everything here
+pub fn register(m: &mut ModuleContext, prefix: &str) -> NeonResult<()> {
+    m.export_function(&format!("{}_from_file", prefix), from_file)?;
+    m.export_function(&format!("{}_to_file", prefix), to_file)?;
+    Ok(())
+}
@@ -21,8 +21,36 @@ impl PreTokenizer {
         })
     }
 
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let data = self
+            .pretok
+            .execute(|pretok| serde_json::to_string(&pretok))
+            .map_err(|e| {
+                exceptions::Exception::py_err(format!(
+                    "Error while attempting to pickle PreTokenizer: {}",
+                    e.to_string()
+                ))
+            })?;
+        Ok(PyBytes::new(py, data.as_bytes()).to_object(py))
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                self.pretok =
+                    Container::Owned(serde_json::from_slice(s.as_bytes()).map_err(|e| {
+                        exceptions::Exception::py_err(format!(
+                            "Error while attempting to unpickle PreTokenizer: {}",
+                            e.to_string()
+                        ))
+                    })?);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     fn pre_tokenize(&self, s: &str) -> PyResult<Vec<(String, Offsets)>> {
-        // TODO: Expose the NormalizedString
         let mut normalized = tk::tokenizer::NormalizedString::from(s);
         ToPyResult(
             self.pretok
@@ -32,6 +60,36 @@ impl PreTokenizer {
     }
 }
 
+/// Python-accessible view over a `NormalizedString`. It exposes both the
+/// `normalized` and `original` strings plus `original_offsets`, so a custom
+/// pre-tokenizer can split on the normalized form yet return offsets that map
+/// back onto the original input even when a normalizer changed its length.
+#[pyclass(dict, module = "tokenizers")]
+pub struct NormalizedString {
+    pub normalized: tk::tokenizer::NormalizedString,
+}
+
+#[pymethods]
+impl NormalizedString {
+    #[getter]
+    fn get_normalized(&self) -> &str {
+        self.normalized.get()
+    }
+
+    #[getter]
+    fn get_original(&self) -> &str {
+        self.normalized.get_original()
+    }
+
+    /// Maps a `(start, end)` range expressed in the normalized string back to
+    /// the matching range in the original input.
+    fn original_offsets(&self, range: (usize, usize)) -> Option<(usize, usize)> {
+        self.normalized
+            .convert_offsets(range.0..range.1)
+            .map(|r| (r.start, r.end))
+    }
+}
+
 #[pyclass(extends=PreTokenizer)]
 pub struct ByteLevel {}
 #[pymethods]
@@ -60,6 +118,10 @@ impl ByteLevel {
         ))
     }
 
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::empty(py))
+    }
+
     #[staticmethod]
     fn alphabet() -> Vec<String> {
         tk::pre_tokenizers::byte_level::ByteLevel::alphabet()
@@ -82,6 +144,10 @@ impl Whitespace {
             },
         ))
     }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::empty(py))
+    }
 }
 
 #[pyclass(extends=PreTokenizer)]
@@ -97,6 +163,10 @@ impl WhitespaceSplit {
             },
         ))
     }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::empty(py))
+    }
 }
 
 #[pyclass(extends=PreTokenizer)]
@@ -120,6 +190,57 @@ impl CharDelimiterSplit {
             },
         ))
     }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &[" "]))
+    }
+}
+
+#[pyclass(extends=PreTokenizer)]
+pub struct Split {}
+#[pymethods]
+impl Split {
+    #[new]
+    #[args(invert = false, literal = false)]
+    fn new(
+        pattern: &str,
+        behavior: &str,
+        invert: bool,
+        literal: bool,
+    ) -> PyResult<(Self, PreTokenizer)> {
+        use tk::pre_tokenizers::split::{SplitBehavior, SplitPattern};
+        let behavior = match behavior {
+            "removed" => SplitBehavior::Removed,
+            "isolated" => SplitBehavior::Isolated,
+            "merged_with_previous" => SplitBehavior::MergedWithPrevious,
+            "merged_with_next" => SplitBehavior::MergedWithNext,
+            "contiguous" => SplitBehavior::Contiguous,
+            _ => {
+                return Err(exceptions::ValueError::py_err(format!(
+                    "Unknown split behavior `{}`",
+                    behavior
+                )))
+            }
+        };
+        // A `literal` pattern matches verbatim; otherwise it is a regex.
+        let pattern = if literal {
+            SplitPattern::String(pattern.to_string())
+        } else {
+            SplitPattern::Regex(pattern.to_string())
+        };
+        Ok((
+            Split {},
+            PreTokenizer {
+                pretok: Container::Owned(Box::new(tk::pre_tokenizers::split::Split::new(
+                    pattern, behavior, invert,
+                ))),
+            },
+        ))
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &["", "removed"]))
+    }
 }
 
 #[pyclass(extends=PreTokenizer)]
@@ -135,6 +256,10 @@ impl BertPreTokenizer {
             },
         ))
     }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::empty(py))
+    }
 }
 
 #[pyclass(extends=PreTokenizer)]
@@ -173,6 +298,185 @@ impl Metaspace {
             },
         ))
     }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::empty(py))
+    }
+}
+
+#[pyclass(extends=PreTokenizer)]
+pub struct DictSplit {}
+#[pymethods]
+impl DictSplit {
+    #[new]
+    #[args(kwargs = "**")]
+    fn new(kwargs: Option<&PyDict>) -> PyResult<(Self, PreTokenizer)> {
+        let mut dict: Option<String> = None;
+
+        if let Some(kwargs) = kwargs {
+            for (key, value) in kwargs {
+                let key: &str = key.extract()?;
+                match key {
+                    "dict" | "dict_path" => dict = Some(value.extract()?),
+                    _ => {
+                        return Err(exceptions::ValueError::py_err(format!(
+                            "Unknown kwarg `{}` for DictSplit",
+                            key
+                        )))
+                    }
+                }
+            }
+        }
+
+        let dict_split = match dict {
+            Some(dict) => {
+                let res: PyResult<tk::pre_tokenizers::dict::DictSplit> =
+                    ToPyResult(tk::pre_tokenizers::dict::DictSplit::from_file(&dict)).into();
+                res?
+            }
+            None => tk::pre_tokenizers::dict::DictSplit::default(),
+        };
+
+        Ok((
+            DictSplit {},
+            PreTokenizer {
+                pretok: Container::Owned(Box::new(dict_split)),
+            },
+        ))
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::empty(py))
+    }
+}
+
+/// Rebuilds a core `Sequence` from a list of `PreTokenizer` Python objects by
+/// round-tripping each one through serde, so the chain owns its own boxed
+/// trait objects independently of the Python wrappers.
+fn build_sequence(
+    py: Python,
+    pretoks: &[PyObject],
+) -> PyResult<tk::pre_tokenizers::sequence::Sequence> {
+    let mut boxed = vec![];
+    for obj in pretoks {
+        let pretok = obj.cast_as::<PreTokenizer>(py).map_err(|_| {
+            exceptions::Exception::py_err("Sequence expects a list of PreTokenizer")
+        })?;
+        let data = pretok
+            .pretok
+            .execute(|p| serde_json::to_string(&p))
+            .map_err(|e| exceptions::Exception::py_err(format!("{}", e)))?;
+        boxed.push(
+            serde_json::from_str(&data)
+                .map_err(|e| exceptions::Exception::py_err(format!("{}", e)))?,
+        );
+    }
+    Ok(tk::pre_tokenizers::sequence::Sequence::new(boxed))
+}
+
+#[pyclass(extends=PreTokenizer)]
+pub struct Sequence {
+    pretoks: Vec<PyObject>,
+}
+#[pymethods]
+impl Sequence {
+    #[new]
+    fn new(pretoks: &PyList) -> PyResult<(Self, PreTokenizer)> {
+        let py = pretoks.py();
+        let items: Vec<PyObject> = pretoks.iter().map(|o| o.to_object(py)).collect();
+        let sequence = build_sequence(py, &items)?;
+        Ok((
+            Sequence { pretoks: items },
+            PreTokenizer {
+                pretok: Container::Owned(Box::new(sequence)),
+            },
+        ))
+    }
+
+    fn __len__(&self) -> usize {
+        self.pretoks.len()
+    }
+
+    fn __getitem__(&self, py: Python, index: usize) -> PyResult<PyObject> {
+        self.pretoks
+            .get(index)
+            .map(|o| o.clone_ref(py))
+            .ok_or_else(|| exceptions::IndexError::py_err("index out of range"))
+    }
+
+    /// Replaces the step at `index`, or removes it when `value` is `None`, then
+    /// rebuilds the underlying chain so `pre_tokenize` reflects the change.
+    fn __setitem__(
+        mut self_: PyRefMut<Self>,
+        index: usize,
+        value: Option<PyObject>,
+    ) -> PyResult<()> {
+        if index >= self_.pretoks.len() {
+            return Err(exceptions::IndexError::py_err("index out of range"));
+        }
+        match value {
+            Some(value) => self_.pretoks[index] = value,
+            None => {
+                self_.pretoks.remove(index);
+            }
+        }
+        let py = self_.py();
+        let items = self_.pretoks.clone();
+        let sequence = build_sequence(py, &items)?;
+        let base: &mut PreTokenizer = self_.as_mut();
+        base.pretok = Container::Owned(Box::new(sequence));
+        Ok(())
+    }
+
+    /// Restores both the underlying chain and the Python-side `pretoks` list, so
+    /// `len()`/`__getitem__` stay consistent with `pre_tokenize` after an
+    /// unpickle (the inherited `__setstate__` only restores the base container).
+    fn __setstate__(mut self_: PyRefMut<Self>, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes = state.extract::<&PyBytes>(py)?;
+        let unpickle_error = |e: serde_json::Error| {
+            exceptions::Exception::py_err(format!(
+                "Error while attempting to unpickle Sequence: {}",
+                e.to_string()
+            ))
+        };
+
+        // The state is the typetag-tagged boxed pre-tokenizer, i.e.
+        // `{"Sequence":{"pretokenizers":[...]}}`, so restore the base container
+        // by deserializing it as a `Box<dyn PreTokenizer>`.
+        let boxed: Box<dyn tk::tokenizer::PreTokenizer> =
+            serde_json::from_slice(bytes.as_bytes()).map_err(unpickle_error)?;
+
+        // Rebuild the inspectable list from the members nested under the
+        // `Sequence` tag, wrapping each one back into a `PreTokenizer` object.
+        let value: serde_json::Value =
+            serde_json::from_slice(bytes.as_bytes()).map_err(unpickle_error)?;
+        let mut pretoks = vec![];
+        let members = value
+            .get("Sequence")
+            .and_then(|v| v.get("pretokenizers"))
+            .and_then(|v| v.as_array());
+        if let Some(members) = members {
+            for member in members {
+                let boxed = serde_json::from_str(&member.to_string()).map_err(unpickle_error)?;
+                let obj = Py::new(
+                    py,
+                    PreTokenizer {
+                        pretok: Container::Owned(boxed),
+                    },
+                )?;
+                pretoks.push(obj.to_object(py));
+            }
+        }
+
+        self_.pretoks = pretoks;
+        let base: &mut PreTokenizer = self_.as_mut();
+        base.pretok = Container::Owned(boxed);
+        Ok(())
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &[PyList::empty(py)]))
+    }
 }
 
 /// Attempt at providing Python the ability to give its own PreTokenizer
@@ -194,7 +498,14 @@ impl tk::tokenizer::PreTokenizer for PyPreTokenizer {
         let gil = Python::acquire_gil();
         let py = gil.python();
 
-        let args = PyTuple::new(py, &[sentence.get()]);
+        let normalized = Py::new(
+            py,
+            NormalizedString {
+                normalized: sentence.clone(),
+            },
+        )
+        .map_err(|_| PyError::from("Unable to expose the NormalizedString to Python"))?;
+        let args = PyTuple::new(py, &[normalized]);
         match self.class.call_method(py, "pre_tokenize", args, None) {
             Ok(res) => Ok(res
                 .cast_as::<PyList>(py)
@@ -4,6 +4,7 @@ use super::utils::Container;
 use pyo3::exceptions;
 use pyo3::prelude::*;
 use pyo3::types::*;
+use std::collections::HashMap;
 
 #[pyclass(dict, module = "tokenizers.processors")]
 pub struct PostProcessor {
@@ -96,6 +97,39 @@ impl RobertaProcessing {
     }
 }
 
+#[pyclass(extends=PostProcessor, module = "tokenizers.processors")]
+pub struct TemplateProcessing {}
+#[pymethods]
+impl TemplateProcessing {
+    #[new]
+    #[args(single = "\"$A\"", pair = "\"$A $B\"", special_tokens = "None")]
+    fn new(
+        single: &str,
+        pair: &str,
+        special_tokens: Option<&PyDict>,
+    ) -> PyResult<(Self, PostProcessor)> {
+        let mut tokens = HashMap::new();
+        if let Some(special_tokens) = special_tokens {
+            for (key, value) in special_tokens {
+                tokens.insert(key.extract::<String>()?, value.extract::<u32>()?);
+            }
+        }
+
+        Ok((
+            TemplateProcessing {},
+            PostProcessor {
+                processor: Container::Owned(Box::new(
+                    tk::processors::template::TemplateProcessing::new(single, pair, tokens),
+                )),
+            },
+        ))
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &["$A", "$A $B"]))
+    }
+}
+
 #[pyclass(extends=PostProcessor, module = "tokenizers.processors")]
 pub struct ByteLevel {}
 #[pymethods]
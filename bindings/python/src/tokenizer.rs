@@ -0,0 +1,28 @@
+extern crate tokenizers as tk;
+
+use super::error::ToPyResult;
+use pyo3::prelude::*;
+
+/// from_file(path: str) -> str
+///
+/// Reads a single, self-describing tokenizer JSON file (normalizer,
+/// pre-tokenizer, model, post-processor, decoder and the truncation/padding
+/// params), validates every component and returns the canonical JSON string.
+#[pyfunction]
+pub fn from_file(path: &str) -> PyResult<String> {
+    let loaded: PyResult<tk::serialization::SerializedTokenizer> =
+        ToPyResult(tk::serialization::SerializedTokenizer::from_file(path)).into();
+    let tokenizer = loaded?;
+    ToPyResult(tokenizer.to_string(true)).into()
+}
+
+/// to_file(path: str, data: str)
+///
+/// Validates a canonical tokenizer JSON document and writes the whole pipeline
+/// to a single file.
+#[pyfunction]
+pub fn to_file(path: &str, data: &str) -> PyResult<()> {
+    let tokenizer: PyResult<tk::serialization::SerializedTokenizer> =
+        ToPyResult(tk::serialization::SerializedTokenizer::from_str(data)).into();
+    ToPyResult(tokenizer?.to_file(path)).into()
+}
@@ -0,0 +1,231 @@
+use crate::tokenizer::{Model, Offsets, Result, Token};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Penalty added to the score whenever a character has to fall back to the
+/// `unk_token` because no vocabulary entry covers it. It is large and negative
+/// so that the Viterbi search only resorts to unknowns when nothing else fits,
+/// while still guaranteeing the lattice always reaches the end of the input.
+const UNK_PENALTY: f64 = -10.0;
+
+/// A Unigram language model, as trained by SentencePiece.
+///
+/// The vocabulary is a list of `(token, log_prob)` entries; tokenization picks
+/// the segmentation that maximizes the sum of the log probabilities using a
+/// Viterbi search over the lattice of all possible pieces.
+#[derive(Serialize, Deserialize)]
+#[serde(from = "UnigramConfig")]
+pub struct Unigram {
+    vocab: Vec<(String, f64)>,
+    unk_token: String,
+
+    #[serde(skip)]
+    token_to_id: HashMap<String, u32>,
+}
+
+/// Serde shadow of `Unigram` used on deserialization so that `token_to_id`,
+/// which is not part of the stored document, is rebuilt from `vocab` rather
+/// than left empty (which would make every input fall back to `unk_token`).
+#[derive(Deserialize)]
+struct UnigramConfig {
+    vocab: Vec<(String, f64)>,
+    unk_token: String,
+}
+
+impl From<UnigramConfig> for Unigram {
+    fn from(config: UnigramConfig) -> Self {
+        Unigram::new(config.vocab, config.unk_token)
+    }
+}
+
+impl Unigram {
+    /// Creates a `Unigram` from a vocabulary of `(token, log_prob)` pairs.
+    pub fn new(vocab: Vec<(String, f64)>, unk_token: String) -> Self {
+        let token_to_id = vocab
+            .iter()
+            .enumerate()
+            .map(|(id, (token, _))| (token.clone(), id as u32))
+            .collect();
+        Unigram {
+            vocab,
+            unk_token,
+            token_to_id,
+        }
+    }
+
+    /// Loads a `Unigram` from a SentencePiece-style vocabulary file, where each
+    /// line holds a token and its log probability separated by a tab.
+    pub fn from_file(vocab: &str, unk_token: String) -> Result<Self> {
+        let file = File::open(vocab)?;
+        let mut entries = vec![];
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let mut parts = line.split('\t');
+            let token = parts.next().unwrap_or("").to_string();
+            let log_prob = parts.next().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
+            entries.push((token, log_prob));
+        }
+        Ok(Unigram::new(entries, unk_token))
+    }
+
+    fn log_prob(&self, id: u32) -> f64 {
+        self.vocab[id as usize].1
+    }
+}
+
+impl Default for Unigram {
+    fn default() -> Self {
+        Unigram::new(vec![], String::from("<unk>"))
+    }
+}
+
+impl Unigram {
+    /// Runs a Viterbi search over the lattice of pieces found in `sentence` and
+    /// returns the best scoring segmentation as a list of `(token, id, offsets)`.
+    fn viterbi(&self, sentence: &str) -> Vec<(String, u32, Offsets)> {
+        let chars: Vec<char> = sentence.chars().collect();
+        let n = chars.len();
+
+        let mut best_score = vec![std::f64::NEG_INFINITY; n + 1];
+        best_score[0] = 0.0;
+        // For every end position, the (start, token_id) the best path came from.
+        let mut back: Vec<Option<(usize, u32)>> = vec![None; n + 1];
+
+        for e in 1..=n {
+            for s in 0..e {
+                let piece: String = chars[s..e].iter().collect();
+                if let Some(&id) = self.token_to_id.get(&piece) {
+                    let cand = best_score[s] + self.log_prob(id);
+                    if cand > best_score[e] {
+                        best_score[e] = cand;
+                        back[e] = Some((s, id));
+                    }
+                }
+            }
+            // Only when no vocabulary entry ends at `e` does the final character
+            // fall back to the unknown token, so the lattice always reaches `n`
+            // for out-of-vocabulary input without ever displacing a real token.
+            if best_score[e] == std::f64::NEG_INFINITY {
+                let s = e - 1;
+                best_score[e] = best_score[s] + UNK_PENALTY;
+                back[e] = None;
+            }
+        }
+
+        // Offsets are the char indices `s..e` we walked over, matching the char
+        // offsets of the pre-tokens they are added to.
+        let unk_id = self
+            .token_to_id
+            .get(&self.unk_token)
+            .copied()
+            .unwrap_or(0);
+
+        let mut tokens = vec![];
+        let mut e = n;
+        while e > 0 {
+            match back[e] {
+                Some((s, id)) => {
+                    let piece: String = chars[s..e].iter().collect();
+                    tokens.push((piece, id, (s, e)));
+                    e = s;
+                }
+                None => {
+                    let s = e - 1;
+                    tokens.push((self.unk_token.clone(), unk_id, (s, e)));
+                    e = s;
+                }
+            }
+        }
+        tokens.reverse();
+        tokens
+    }
+}
+
+#[typetag::serde]
+impl Model for Unigram {
+    fn tokenize(&self, sentence: Vec<(String, Offsets)>) -> Result<Vec<Token>> {
+        let mut output = vec![];
+        for (piece, (start, _)) in sentence {
+            for (value, id, (s, e)) in self.viterbi(&piece) {
+                output.push(Token::new(id, value, (start + s, start + e)));
+            }
+        }
+        Ok(output)
+    }
+
+    fn token_to_id(&self, token: &str) -> Option<u32> {
+        self.token_to_id.get(token).copied()
+    }
+
+    fn id_to_token(&self, id: u32) -> Option<String> {
+        self.vocab.get(id as usize).map(|(token, _)| token.clone())
+    }
+
+    fn get_vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+
+    fn save(&self, folder: &Path, name: Option<&str>) -> Result<Vec<PathBuf>> {
+        let vocab_path = folder.join(match name {
+            Some(name) => format!("{}-unigram.json", name),
+            None => "unigram.json".to_string(),
+        });
+        std::fs::write(&vocab_path, serde_json::to_string(self)?)?;
+        Ok(vec![vocab_path])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Unigram {
+        Unigram::new(
+            vec![
+                ("<unk>".into(), 0.0),
+                ("ab".into(), -1.0),
+                ("cd".into(), -1.0),
+                ("abc".into(), -5.0),
+                ("a".into(), -3.0),
+                ("b".into(), -3.0),
+                ("c".into(), -3.0),
+                ("d".into(), -3.0),
+            ],
+            "<unk>".into(),
+        )
+    }
+
+    #[test]
+    fn prefers_longer_pieces() {
+        let model = sample();
+        let tokens = model
+            .tokenize(vec![("abcd".into(), (0, 4))])
+            .unwrap();
+        let values: Vec<_> = tokens.iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(values, vec!["ab", "cd"]);
+        assert_eq!(tokens[0].offsets, (0, 2));
+        assert_eq!(tokens[1].offsets, (2, 4));
+    }
+
+    #[test]
+    fn tokenizes_after_round_trip() {
+        let model = sample();
+        let json = serde_json::to_string(&model).unwrap();
+        let restored: Unigram = serde_json::from_str(&json).unwrap();
+        let tokens = restored.tokenize(vec![("abcd".into(), (0, 4))]).unwrap();
+        let values: Vec<_> = tokens.iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(values, vec!["ab", "cd"]);
+        assert_eq!(restored.token_to_id("ab"), Some(1));
+    }
+
+    #[test]
+    fn falls_back_to_unk() {
+        let model = sample();
+        let tokens = model.tokenize(vec![("xy".into(), (0, 2))]).unwrap();
+        let values: Vec<_> = tokens.iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(values, vec!["<unk>", "<unk>"]);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::tokenizer::{NormalizedString, Offsets, PreTokenizer, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// A node of the prefix trie holding the word dictionary. `freq` is set on the
+/// node that terminates a word, so walking the trie from a start position
+/// yields every dictionary word that begins there in a single pass.
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    freq: Option<f64>,
+}
+
+impl TrieNode {
+    fn insert(&mut self, word: &str, freq: f64) {
+        let mut node = self;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.freq = Some(freq);
+    }
+}
+
+/// Segments whitespace-free text (e.g. Chinese) into words using a frequency
+/// dictionary. The dictionary is loaded into a prefix trie; for each sentence a
+/// DAG of all dictionary words is built and a max-probability dynamic program
+/// (scanning right-to-left) picks the most likely segmentation, falling back to
+/// single codepoints for spans that no word covers.
+#[derive(Serialize, Deserialize)]
+#[serde(from = "DictSplitConfig")]
+pub struct DictSplit {
+    vocab: Vec<(String, f64)>,
+
+    #[serde(skip)]
+    trie: TrieNode,
+    #[serde(skip)]
+    log_total: f64,
+}
+
+/// Serde shadow of `DictSplit` used on deserialization so that `trie` and
+/// `log_total`, which are derived rather than stored, are rebuilt from `vocab`
+/// instead of left empty (which would segment every input into codepoints).
+#[derive(Deserialize)]
+struct DictSplitConfig {
+    vocab: Vec<(String, f64)>,
+}
+
+impl From<DictSplitConfig> for DictSplit {
+    fn from(config: DictSplitConfig) -> Self {
+        DictSplit::new(config.vocab)
+    }
+}
+
+impl DictSplit {
+    /// Builds a segmenter from a list of `(word, frequency)` entries.
+    pub fn new(vocab: Vec<(String, f64)>) -> Self {
+        let mut trie = TrieNode::default();
+        let mut total = 0.0;
+        for (word, freq) in &vocab {
+            trie.insert(word, *freq);
+            total += *freq;
+        }
+        DictSplit {
+            vocab,
+            trie,
+            log_total: total.max(1.0).ln(),
+        }
+    }
+
+    /// Loads a jieba-style dictionary file whose lines hold a word, its
+    /// frequency and an optional part-of-speech tag separated by spaces.
+    pub fn from_file(dict: &str) -> Result<Self> {
+        let file = File::open(dict)?;
+        let mut entries = vec![];
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let mut parts = line.split_whitespace();
+            let word = match parts.next() {
+                Some(word) => word.to_string(),
+                None => continue,
+            };
+            let freq = parts.next().and_then(|s| s.parse::<f64>().ok()).unwrap_or(1.0);
+            entries.push((word, freq));
+        }
+        Ok(DictSplit::new(entries))
+    }
+
+    /// Returns the log probability of a word with the given raw frequency,
+    /// using `1` for words absent from the dictionary (as jieba does).
+    fn log_prob(&self, freq: f64) -> f64 {
+        freq.max(1.0).ln() - self.log_total
+    }
+
+    /// Runs the max-probability segmentation over `chars`, returning the end
+    /// char index of each segment in order.
+    fn segment(&self, chars: &[char]) -> Vec<usize> {
+        let n = chars.len();
+        // route[i] = (best score from i to the end, next char index to jump to)
+        let mut route = vec![(0.0f64, n); n + 1];
+        for i in (0..n).rev() {
+            let mut best = (std::f64::NEG_INFINITY, i + 1);
+            // Single-codepoint fallback always available.
+            let fallback = self.log_prob(1.0) + route[i + 1].0;
+            if fallback > best.0 {
+                best = (fallback, i + 1);
+            }
+            // Every dictionary word starting at `i`.
+            let mut node = &self.trie;
+            let mut j = i;
+            while j < n {
+                match node.children.get(&chars[j]) {
+                    Some(child) => {
+                        node = child;
+                        j += 1;
+                        if let Some(freq) = node.freq {
+                            let score = self.log_prob(freq) + route[j].0;
+                            if score > best.0 {
+                                best = (score, j);
+                            }
+                        }
+                    }
+                    None => break,
+                }
+            }
+            route[i] = best;
+        }
+
+        let mut ends = vec![];
+        let mut i = 0;
+        while i < n {
+            let next = route[i].1;
+            ends.push(next);
+            i = next;
+        }
+        ends
+    }
+}
+
+impl Default for DictSplit {
+    fn default() -> Self {
+        DictSplit::new(vec![])
+    }
+}
+
+#[typetag::serde]
+impl PreTokenizer for DictSplit {
+    fn pre_tokenize(&self, normalized: &mut NormalizedString) -> Result<Vec<(String, Offsets)>> {
+        let text = normalized.get();
+        let chars: Vec<char> = text.chars().collect();
+
+        // Byte boundary for each char index, used only to slice out the piece
+        // text; the emitted offsets are char indices to match the rest of the
+        // pipeline (see `CharDelimiterSplit`/`Metaspace`).
+        let mut byte_offsets = Vec::with_capacity(chars.len() + 1);
+        let mut byte = 0;
+        byte_offsets.push(0);
+        for c in &chars {
+            byte += c.len_utf8();
+            byte_offsets.push(byte);
+        }
+
+        let mut words = vec![];
+        let mut start = 0;
+        for end in self.segment(&chars) {
+            let piece = text[byte_offsets[start]..byte_offsets[end]].to_string();
+            words.push((piece, (start, end)));
+            start = end;
+        }
+        Ok(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DictSplit {
+        DictSplit::new(
+            vec![
+                ("北京".into(), 40.0),
+                ("大学".into(), 40.0),
+                ("北京大学".into(), 1.0),
+                ("生".into(), 3.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn prefers_high_probability_split() {
+        let pretok = sample();
+        let mut input = NormalizedString::from("北京大学");
+        let res = pretok.pre_tokenize(&mut input).unwrap();
+        let words: Vec<_> = res.iter().map(|(w, _)| w.as_str()).collect();
+        assert_eq!(words, vec!["北京", "大学"]);
+        assert_eq!(res[0].1, (0, 2));
+        assert_eq!(res[1].1, (2, 4));
+    }
+
+    #[test]
+    fn segments_after_round_trip() {
+        let pretok = sample();
+        let json = serde_json::to_string(&pretok).unwrap();
+        let restored: DictSplit = serde_json::from_str(&json).unwrap();
+        let mut input = NormalizedString::from("北京大学");
+        let res = restored.pre_tokenize(&mut input).unwrap();
+        let words: Vec<_> = res.iter().map(|(w, _)| w.as_str()).collect();
+        assert_eq!(words, vec!["北京", "大学"]);
+    }
+
+    #[test]
+    fn falls_back_to_single_codepoints() {
+        let pretok = sample();
+        let mut input = NormalizedString::from("猫");
+        let res = pretok.pre_tokenize(&mut input).unwrap();
+        let words: Vec<_> = res.iter().map(|(w, _)| w.as_str()).collect();
+        assert_eq!(words, vec!["猫"]);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::tokenizer::{NormalizedString, Offsets, PreTokenizer, Result};
+use serde::{Deserialize, Serialize};
+
+/// Chains several pre-tokenizers, running them in order so that each one
+/// further splits the pieces produced by the previous one (e.g. a
+/// `WhitespaceSplit` followed by a `ByteLevel`). Offsets are threaded through
+/// every step so the final pieces still point back into the original input.
+#[derive(Serialize, Deserialize)]
+pub struct Sequence {
+    pretokenizers: Vec<Box<dyn PreTokenizer>>,
+}
+
+impl Sequence {
+    pub fn new(pretokenizers: Vec<Box<dyn PreTokenizer>>) -> Self {
+        Sequence { pretokenizers }
+    }
+}
+
+#[typetag::serde]
+impl PreTokenizer for Sequence {
+    fn pre_tokenize(&self, normalized: &mut NormalizedString) -> Result<Vec<(String, Offsets)>> {
+        // Offsets are char indices throughout the pipeline (see
+        // `CharDelimiterSplit`/`Metaspace`), so the seed spans the whole input
+        // in chars and each child's char offsets are rebased onto `start`.
+        let mut pieces = vec![(
+            normalized.get().to_string(),
+            (0, normalized.get().chars().count()) as Offsets,
+        )];
+        for pretokenizer in &self.pretokenizers {
+            let mut next = vec![];
+            for (piece, (start, _)) in pieces {
+                let mut sub = NormalizedString::from(piece.as_str());
+                for (value, (s, e)) in pretokenizer.pre_tokenize(&mut sub)? {
+                    next.push((value, (start + s, start + e)));
+                }
+            }
+            pieces = next;
+        }
+        Ok(pieces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pre_tokenizers::delimiter::CharDelimiterSplit;
+
+    #[test]
+    fn chains_in_order() {
+        let pretok = Sequence::new(vec![
+            Box::new(CharDelimiterSplit::new('-')),
+            Box::new(CharDelimiterSplit::new('_')),
+        ]);
+        let mut input = NormalizedString::from("a-b_c-d");
+        let res = pretok.pre_tokenize(&mut input).unwrap();
+        assert_eq!(
+            &res,
+            &[
+                ("a".into(), (0, 1)),
+                ("b".into(), (2, 3)),
+                ("c".into(), (4, 5)),
+                ("d".into(), (6, 7)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::tokenizer::{NormalizedString, Offsets, PreTokenizer, Result};
+use once_cell::sync::OnceCell;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// The pattern a `Split` pre-tokenizer matches on: either a literal string or
+/// a regular expression.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum SplitPattern {
+    String(String),
+    Regex(String),
+}
+
+/// What happens to the delimiter matched by a `Split` pre-tokenizer.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum SplitBehavior {
+    /// Drop the matched delimiter entirely.
+    Removed,
+    /// Keep the matched delimiter as its own piece.
+    Isolated,
+    /// Attach the matched delimiter to the piece on its left.
+    MergedWithPrevious,
+    /// Attach the matched delimiter to the piece on its right.
+    MergedWithNext,
+    /// Collapse adjacent matched delimiters into a single token.
+    Contiguous,
+}
+
+/// A configurable splitter driven by a pattern and a behavior, generalizing
+/// `CharDelimiterSplit` (which can only split on a single `char`) to arbitrary
+/// literals or regular expressions.
+#[derive(Serialize, Deserialize)]
+#[serde(from = "SplitConfig")]
+pub struct Split {
+    pattern: SplitPattern,
+    behavior: SplitBehavior,
+    /// When set, the pattern is treated as the complement of the delimiter, so
+    /// the matched spans become the kept pieces and the gaps the delimiters.
+    invert: bool,
+
+    /// The pattern compiled once and cached, so `pre_tokenize` does not re-parse
+    /// the regex for every sentence. Not serialized; rebuilt lazily on load.
+    #[serde(skip)]
+    compiled: OnceCell<Regex>,
+}
+
+/// Serde shadow of `Split` so the cached `compiled` regex is reset (and rebuilt
+/// lazily) on deserialization rather than being part of the stored document.
+#[derive(Deserialize)]
+struct SplitConfig {
+    pattern: SplitPattern,
+    behavior: SplitBehavior,
+    invert: bool,
+}
+
+impl From<SplitConfig> for Split {
+    fn from(config: SplitConfig) -> Self {
+        Split::new(config.pattern, config.behavior, config.invert)
+    }
+}
+
+impl Split {
+    pub fn new(pattern: SplitPattern, behavior: SplitBehavior, invert: bool) -> Self {
+        Split {
+            pattern,
+            behavior,
+            invert,
+            compiled: OnceCell::new(),
+        }
+    }
+
+    fn regex(&self) -> Result<&Regex> {
+        self.compiled.get_or_try_init(|| {
+            let source = match &self.pattern {
+                SplitPattern::String(s) => regex::escape(s),
+                SplitPattern::Regex(r) => r.clone(),
+            };
+            Ok(Regex::new(&source)?)
+        })
+    }
+}
+
+#[typetag::serde]
+impl PreTokenizer for Split {
+    fn pre_tokenize(&self, normalized: &mut NormalizedString) -> Result<Vec<(String, Offsets)>> {
+        let regex = self.regex()?;
+        let text = normalized.get();
+
+        // Split the input into a flat list of pieces, each flagged as a match
+        // (the delimiter) or not, carrying its byte offsets into the input.
+        let mut pieces: Vec<(Offsets, bool)> = vec![];
+        let mut prev = 0;
+        for m in regex.find_iter(text) {
+            if m.start() > prev {
+                pieces.push(((prev, m.start()), self.invert));
+            }
+            pieces.push(((m.start(), m.end()), !self.invert));
+            prev = m.end();
+        }
+        if prev < text.len() {
+            pieces.push(((prev, text.len()), self.invert));
+        }
+
+        // Apply the behavior by (optionally) merging matched delimiters into a
+        // neighboring piece or collapsing adjacent ones.
+        let mut words: Vec<Offsets> = vec![];
+        let mut prev_was_match = false;
+        for (offsets, is_match) in pieces {
+            if is_match {
+                match self.behavior {
+                    SplitBehavior::Removed => {}
+                    SplitBehavior::Isolated | SplitBehavior::MergedWithNext => words.push(offsets),
+                    SplitBehavior::MergedWithPrevious => {
+                        if let Some(last) = words.last_mut() {
+                            last.1 = offsets.1;
+                        } else {
+                            words.push(offsets);
+                        }
+                    }
+                    SplitBehavior::Contiguous => {
+                        if prev_was_match {
+                            if let Some(last) = words.last_mut() {
+                                last.1 = offsets.1;
+                            } else {
+                                words.push(offsets);
+                            }
+                        } else {
+                            words.push(offsets);
+                        }
+                    }
+                }
+            } else if let SplitBehavior::MergedWithNext = self.behavior {
+                if let Some(last) = words.last_mut() {
+                    last.1 = offsets.1;
+                } else {
+                    words.push(offsets);
+                }
+            } else {
+                words.push(offsets);
+            }
+            prev_was_match = is_match;
+        }
+
+        // The spans above are byte indices; the rest of the pipeline works in
+        // char offsets (see `CharDelimiterSplit`/`Metaspace`), so translate each
+        // boundary to its char index before emitting.
+        let mut byte_to_char = vec![0; text.len() + 1];
+        let mut char_index = 0;
+        for (byte, _) in text.char_indices() {
+            byte_to_char[byte] = char_index;
+            char_index += 1;
+        }
+        byte_to_char[text.len()] = char_index;
+
+        Ok(words
+            .into_iter()
+            .filter(|(s, e)| e > s)
+            .map(|(s, e)| (text[s..e].to_string(), (byte_to_char[s], byte_to_char[e])))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(pattern: SplitPattern, behavior: SplitBehavior) -> Vec<(String, Offsets)> {
+        let pretok = Split::new(pattern, behavior, false);
+        let mut input = NormalizedString::from("the-quick-fox");
+        pretok.pre_tokenize(&mut input).unwrap()
+    }
+
+    #[test]
+    fn removed() {
+        let res = split(SplitPattern::String("-".into()), SplitBehavior::Removed);
+        assert_eq!(
+            &res,
+            &[
+                ("the".into(), (0, 3)),
+                ("quick".into(), (4, 9)),
+                ("fox".into(), (10, 13)),
+            ]
+        );
+    }
+
+    #[test]
+    fn isolated() {
+        let res = split(SplitPattern::String("-".into()), SplitBehavior::Isolated);
+        assert_eq!(
+            &res,
+            &[
+                ("the".into(), (0, 3)),
+                ("-".into(), (3, 4)),
+                ("quick".into(), (4, 9)),
+                ("-".into(), (9, 10)),
+                ("fox".into(), (10, 13)),
+            ]
+        );
+    }
+
+    #[test]
+    fn merged_with_previous() {
+        let res = split(
+            SplitPattern::String("-".into()),
+            SplitBehavior::MergedWithPrevious,
+        );
+        assert_eq!(
+            &res,
+            &[
+                ("the-".into(), (0, 4)),
+                ("quick-".into(), (4, 10)),
+                ("fox".into(), (10, 13)),
+            ]
+        );
+    }
+
+    #[test]
+    fn contiguous() {
+        let pretok = Split::new(
+            SplitPattern::Regex("-".into()),
+            SplitBehavior::Contiguous,
+            false,
+        );
+        let mut input = NormalizedString::from("a--b");
+        let res = pretok.pre_tokenize(&mut input).unwrap();
+        assert_eq!(
+            &res,
+            &[("a".into(), (0, 1)), ("--".into(), (1, 3)), ("b".into(), (3, 4)),]
+        );
+    }
+
+    #[test]
+    fn char_offsets_for_multibyte() {
+        let pretok = Split::new(SplitPattern::String("-".into()), SplitBehavior::Removed, false);
+        let mut input = NormalizedString::from("é-x");
+        let res = pretok.pre_tokenize(&mut input).unwrap();
+        assert_eq!(&res, &[("é".into(), (0, 1)), ("x".into(), (2, 3)),]);
+    }
+
+    #[test]
+    fn inverted() {
+        let pretok = Split::new(
+            SplitPattern::Regex("[a-z]+".into()),
+            SplitBehavior::Removed,
+            true,
+        );
+        let mut input = NormalizedString::from("ab-cd");
+        let res = pretok.pre_tokenize(&mut input).unwrap();
+        assert_eq!(&res, &[("ab".into(), (0, 2)), ("cd".into(), (3, 5)),]);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::tokenizer::{Encoding, PostProcessor, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A single piece of a template: either a placeholder for one of the input
+/// sequences, or a named special token that the template inserts itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Piece {
+    /// `$A` or `$B` — replaced by the tokens of the matching input sequence.
+    Sequence { id: Sequence, type_id: u32 },
+    /// A named special token, e.g. `[CLS]`, inserted with the given `type_id`.
+    SpecialToken { id: String, type_id: u32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Sequence {
+    A,
+    B,
+}
+
+/// A configurable post-processor driven by a pair of templates written as
+/// ordered token sequences, e.g. `"[CLS] $A [SEP]"` for a single sequence and
+/// `"[CLS] $A [SEP] $B [SEP]"` for a pair. The real sequences are interleaved
+/// where `$A`/`$B` appear and the named special tokens are inserted elsewhere,
+/// which subsumes the hard-coded `BertProcessing`/`RobertaProcessing` types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateProcessing {
+    single: Vec<Piece>,
+    pair: Vec<Piece>,
+    special_tokens: HashMap<String, u32>,
+}
+
+impl TemplateProcessing {
+    pub fn new(
+        single: &str,
+        pair: &str,
+        special_tokens: HashMap<String, u32>,
+    ) -> Self {
+        TemplateProcessing {
+            single: Self::parse(single),
+            pair: Self::parse(pair),
+            special_tokens,
+        }
+    }
+
+    /// Parses a template string into an ordered list of pieces. A piece prefixed
+    /// with `$` is a sequence placeholder; anything else is a named special
+    /// token. Each piece may carry an explicit `:id` suffix (e.g. `$B:0`) to set
+    /// its `type_id`; absent that, `$A` defaults to `0`, `$B` to `1`, and a
+    /// special token inherits the type id of the segment it follows. This lets a
+    /// template reproduce both Bert (`$B` ⇒ type id 1) and Roberta (`$B:0`, both
+    /// segments on type id 0) without hardwiring `$B ⇒ 1`.
+    fn parse(template: &str) -> Vec<Piece> {
+        let mut pieces = vec![];
+        let mut current = 0;
+        for tok in template.split_whitespace() {
+            let mut parts = tok.splitn(2, ':');
+            let name = parts.next().unwrap_or("");
+            let explicit = parts.next().and_then(|s| s.parse::<u32>().ok());
+            match name {
+                "$A" => {
+                    let type_id = explicit.unwrap_or(0);
+                    current = type_id;
+                    pieces.push(Piece::Sequence {
+                        id: Sequence::A,
+                        type_id,
+                    });
+                }
+                "$B" => {
+                    let type_id = explicit.unwrap_or(1);
+                    current = type_id;
+                    pieces.push(Piece::Sequence {
+                        id: Sequence::B,
+                        type_id,
+                    });
+                }
+                special => pieces.push(Piece::SpecialToken {
+                    id: special.to_string(),
+                    type_id: explicit.unwrap_or(current),
+                }),
+            }
+        }
+        pieces
+    }
+
+    fn template(&self, is_pair: bool) -> &[Piece] {
+        if is_pair {
+            &self.pair
+        } else {
+            &self.single
+        }
+    }
+
+    fn apply(
+        &self,
+        template: &[Piece],
+        encoding: Encoding,
+        pair: Option<Encoding>,
+    ) -> Encoding {
+        let mut ids = vec![];
+        let mut type_ids = vec![];
+        let mut tokens = vec![];
+        let mut offsets = vec![];
+        let mut special_tokens_mask = vec![];
+        let mut attention_mask = vec![];
+
+        let mut push_sequence = |enc: &Encoding,
+                                 type_id: u32,
+                                 ids: &mut Vec<u32>,
+                                 type_ids: &mut Vec<u32>,
+                                 tokens: &mut Vec<String>,
+                                 offsets: &mut Vec<(usize, usize)>,
+                                 special_tokens_mask: &mut Vec<u32>,
+                                 attention_mask: &mut Vec<u32>| {
+            for id in enc.get_ids() {
+                ids.push(*id);
+                type_ids.push(type_id);
+                special_tokens_mask.push(0);
+                attention_mask.push(1);
+            }
+            tokens.extend_from_slice(enc.get_tokens());
+            offsets.extend_from_slice(enc.get_offsets());
+        };
+
+        for piece in template {
+            match piece {
+                Piece::Sequence { id, type_id } => {
+                    let enc = match id {
+                        Sequence::A => &encoding,
+                        Sequence::B => pair
+                            .as_ref()
+                            .expect("pair template requires a second sequence"),
+                    };
+                    push_sequence(
+                        enc,
+                        *type_id,
+                        &mut ids,
+                        &mut type_ids,
+                        &mut tokens,
+                        &mut offsets,
+                        &mut special_tokens_mask,
+                        &mut attention_mask,
+                    );
+                }
+                Piece::SpecialToken { id, type_id } => {
+                    if let Some(special_id) = self.special_tokens.get(id) {
+                        ids.push(*special_id);
+                        type_ids.push(*type_id);
+                        tokens.push(id.clone());
+                        offsets.push((0, 0));
+                        special_tokens_mask.push(1);
+                        attention_mask.push(1);
+                    }
+                }
+            }
+        }
+
+        Encoding::new(
+            ids,
+            type_ids,
+            tokens,
+            offsets,
+            special_tokens_mask,
+            attention_mask,
+            vec![],
+        )
+    }
+}
+
+#[typetag::serde]
+impl PostProcessor for TemplateProcessing {
+    fn added_tokens(&self, is_pair: bool) -> usize {
+        // Count only special tokens that `apply` will actually insert, i.e. the
+        // ones resolvable through the `special_tokens` map; otherwise the
+        // truncation reservation would overcount the real inserted length.
+        self.template(is_pair)
+            .iter()
+            .filter(|p| match p {
+                Piece::SpecialToken { id, .. } => self.special_tokens.contains_key(id),
+                _ => false,
+            })
+            .count()
+    }
+
+    fn process(
+        &self,
+        encoding: Encoding,
+        pair_encoding: Option<Encoding>,
+        add_special_tokens: bool,
+    ) -> Result<Encoding> {
+        if !add_special_tokens {
+            return PostProcessor::default_process(encoding, pair_encoding, add_special_tokens);
+        }
+        let is_pair = pair_encoding.is_some();
+        Ok(self.apply(self.template(is_pair), encoding, pair_encoding))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::tokenizer::{Decoder, Model, Normalizer, PostProcessor, PreTokenizer, Result};
+use crate::utils::{PaddingParams, TruncationParams};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A single, self-describing JSON document capturing an entire tokenization
+/// pipeline: the normalizer, pre-tokenizer, model (with its vocab/merges
+/// inlined), post-processor and decoder, plus the truncation/padding params.
+///
+/// Every boxed component is embedded by its serde tag (each already uses
+/// `typetag::serde`), so the whole pipeline round-trips through one file with
+/// no external path dependencies — unlike the multi-file `Model::save` flow.
+///
+/// This type owns the on-disk format only; it validates and normalizes the
+/// canonical document and hands back the typed components, leaving the caller
+/// to assemble them into a running pipeline. It deliberately does not wrap a
+/// runtime tokenizer so that the format can be loaded, checked and re-emitted
+/// without pulling in the full tokenization machinery.
+#[derive(Serialize, Deserialize)]
+pub struct SerializedTokenizer {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalizer: Option<Box<dyn Normalizer>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_tokenizer: Option<Box<dyn PreTokenizer>>,
+    pub model: Box<dyn Model>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_processor: Option<Box<dyn PostProcessor>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoder: Option<Box<dyn Decoder>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncation: Option<TruncationParams>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub padding: Option<PaddingParams>,
+}
+
+impl SerializedTokenizer {
+    /// Parses the canonical document from its JSON representation.
+    pub fn from_str(data: &str) -> Result<Self> {
+        Ok(serde_json::from_str(data)?)
+    }
+
+    /// Renders the canonical document to JSON, pretty-printed when `pretty`.
+    pub fn to_string(&self, pretty: bool) -> Result<String> {
+        let data = if pretty {
+            serde_json::to_string_pretty(self)?
+        } else {
+            serde_json::to_string(self)?
+        };
+        Ok(data)
+    }
+
+    /// Loads the whole pipeline from a single JSON file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Writes the whole pipeline to a single JSON file.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        std::fs::write(path, self.to_string(true)?)?;
+        Ok(())
+    }
+}
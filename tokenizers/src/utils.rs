@@ -1,23 +1,57 @@
 use crate::tokenizer::{Encoding, PaddingDirection, Result};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TruncationParams {
     pub max_length: usize,
     pub strategy: TruncationStrategy,
     pub stride: usize,
+    /// When set, the tokens discarded by truncation are not dropped but emitted
+    /// as a list of overflowing `Encoding`s (sliding windows) stored on the
+    /// returned encoding, so long documents can be processed window by window.
+    pub return_overflowing: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaddingParams {
     pub strategy: PaddingStrategy,
     pub direction: PaddingDirection,
     pub pad_id: u32,
     pub pad_type_id: u32,
     pub pad_token: String,
+    /// When set, the padding length computed from `strategy` is rounded up to
+    /// the next multiple of this value. Aligning the sequence length to a
+    /// multiple of 8 (fp16) or 16 lets matrix-multiply kernels hit the GPU
+    /// tensor cores.
+    pub pad_to_multiple_of: Option<usize>,
 }
 
-#[derive(Debug, Clone)]
+impl Default for TruncationParams {
+    fn default() -> Self {
+        TruncationParams {
+            max_length: 0,
+            strategy: TruncationStrategy::LongestFirst,
+            stride: 0,
+            return_overflowing: false,
+        }
+    }
+}
+
+impl Default for PaddingParams {
+    fn default() -> Self {
+        PaddingParams {
+            strategy: PaddingStrategy::BatchLongest,
+            direction: PaddingDirection::Right,
+            pad_id: 0,
+            pad_type_id: 0,
+            pad_token: String::from("[PAD]"),
+            pad_to_multiple_of: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PaddingStrategy {
     BatchLongest,
     Fixed(usize),
@@ -44,7 +78,7 @@ impl std::fmt::Display for Error {
 }
 impl std::error::Error for Error {}
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TruncationStrategy {
     LongestFirst,
     OnlyFirst,
@@ -81,6 +115,14 @@ pub fn truncate_encodings(
         return Ok((encoding, pair_encoding));
     };
 
+    // Keep a copy of the full sequences so we can slice the overflowing windows
+    // out of them once the truncation has decided how many tokens to keep.
+    let (first_full, second_full) = if params.return_overflowing {
+        (Some(encoding.clone()), pair_encoding.clone())
+    } else {
+        (None, None)
+    };
+
     match params.strategy {
         TruncationStrategy::LongestFirst => {
             let mut n_first = encoding.get_ids().len();
@@ -116,9 +158,50 @@ pub fn truncate_encodings(
         }
     }
 
+    if let Some(first_full) = first_full {
+        let kept = encoding.get_ids().len();
+        encoding.set_overflowing(overflowing_windows(&first_full, kept, params.stride));
+    }
+    if let (Some(second_full), Some(pair)) = (second_full, pair_encoding.as_mut()) {
+        let kept = pair.get_ids().len();
+        pair.set_overflowing(overflowing_windows(&second_full, kept, params.stride));
+    }
+
     Ok((encoding, pair_encoding))
 }
 
+/// Slices `encoding` into the sliding windows that follow the first `kept`
+/// tokens. Each successive window starts `stride` tokens before the previous
+/// window's end, i.e. `window_k` covers tokens `[k*(kept-stride) ..
+/// k*(kept-stride)+kept]`, until the sequence is exhausted.
+fn overflowing_windows(encoding: &Encoding, kept: usize, stride: usize) -> Vec<Encoding> {
+    let len = encoding.get_ids().len();
+    let mut windows = vec![];
+    if kept == 0 || kept <= stride || len <= kept {
+        return windows;
+    }
+
+    let step = kept - stride;
+    let mut start = step;
+    while start < len {
+        let end = std::cmp::min(start + kept, len);
+        windows.push(Encoding::new(
+            encoding.get_ids()[start..end].to_vec(),
+            encoding.get_type_ids()[start..end].to_vec(),
+            encoding.get_tokens()[start..end].to_vec(),
+            encoding.get_offsets()[start..end].to_vec(),
+            encoding.get_special_tokens_mask()[start..end].to_vec(),
+            encoding.get_attention_mask()[start..end].to_vec(),
+            vec![],
+        ));
+        if end == len {
+            break;
+        }
+        start += step;
+    }
+    windows
+}
+
 pub fn pad_encodings(
     mut encodings: Vec<Encoding>,
     params: &PaddingParams,
@@ -127,7 +210,7 @@ pub fn pad_encodings(
         return Ok(encodings);
     }
 
-    let pad_length = match params.strategy {
+    let mut pad_length = match params.strategy {
         PaddingStrategy::Fixed(size) => size,
         PaddingStrategy::BatchLongest => encodings
             .par_iter()
@@ -136,6 +219,12 @@ pub fn pad_encodings(
             .unwrap(),
     };
 
+    if let Some(multiple) = params.pad_to_multiple_of {
+        if multiple > 0 && pad_length % multiple != 0 {
+            pad_length += multiple - pad_length % multiple;
+        }
+    }
+
     encodings.par_iter_mut().for_each(|encoding| {
         encoding.pad(
             pad_length,